@@ -1,11 +1,14 @@
 // SPDX-License-Identifier: GPL-3.0-only
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::time::Instant;
 
 use cosmic::app::{Command, Core};
 use cosmic::applet::menu_button;
+use cosmic::cosmic_config::{self, CosmicConfigEntry};
 use cosmic::iced::alignment::{Horizontal, Vertical};
 use cosmic::iced::wayland::popup::{destroy_popup, get_popup};
+use cosmic::iced::widget::canvas::Canvas;
 use cosmic::iced::window::Id;
 use cosmic::iced::{time, Alignment, Length, Limits, Subscription};
 use cosmic::iced_core::text::Wrap;
@@ -15,16 +18,28 @@ use cosmic::widget::settings::item_row;
 use cosmic::widget::{
     button, column, container, horizontal_space, icon, list_column, row as row_mod,
 };
-use cosmic::widget::{text, toggler};
+use cosmic::widget::{text, text_input, toggler};
 use cosmic::{Application, Element, Theme};
 
+use crate::config::{
+    filter_allows, parse_refresh_interval, validate_filter_pattern, ProcessSortKey, RegexFilter,
+    VitalsAppletConfig, MINIMUM_HISTORY_LENGTH, MINIMUM_PROCESS_COUNT,
+};
 use crate::fl;
+use crate::sparkline::Sparkline;
+
+#[cfg(feature = "battery")]
+use crate::battery::{get_battery_stats, get_battery_usage};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum StatType {
     Ram(String),
     Disk(String),
     MaxTemp(String),
+    Cpu(String),
+    Network(String),
+    #[cfg(feature = "battery")]
+    Battery(String),
 }
 
 #[derive(Debug, Clone)]
@@ -36,7 +51,6 @@ pub struct Stat {
 
 /// This is the struct that represents your application.
 /// It is used to define the data that will be used by your application.
-#[derive(Default)]
 pub struct YourApp {
     /// Application state which is managed by the COSMIC runtime.
     core: Core,
@@ -46,6 +60,85 @@ pub struct YourApp {
     ram_stat_toggle: bool,
     disk_stat_toggle: bool,
     temp_stat_toggle: bool,
+    cpu_stat_toggle: bool,
+    network_stat_toggle: bool,
+    #[cfg(feature = "battery")]
+    battery_stat_toggle: bool,
+    process_stat_toggle: bool,
+    /// Long-lived handle so CPU load (and per-process CPU%) can be read as a
+    /// delta between refreshes.
+    system: sysinfo::System,
+    last_cpu_refresh: Option<Instant>,
+    process_prev_disk_usage: HashMap<sysinfo::Pid, (u64, u64)>,
+    process_disk_rates: HashMap<sysinfo::Pid, (f64, f64)>,
+    process_count: usize,
+    process_count_input: String,
+    process_count_error: Option<String>,
+    /// Long-lived handle so transfer rates can be read as a delta between refreshes.
+    networks: sysinfo::Networks,
+    last_network_sample: Option<Instant>,
+    network_prev_bytes: HashMap<String, (u64, u64)>,
+    network_rates: HashMap<String, (f64, f64)>,
+    config_handler: Option<cosmic_config::Config>,
+    config: VitalsAppletConfig,
+    refresh_interval: std::time::Duration,
+    refresh_interval_input: String,
+    refresh_interval_error: Option<String>,
+    /// Ring buffers of recent samples, one per currently-selected stat.
+    history: HashMap<StatType, VecDeque<f64>>,
+    history_length: usize,
+    history_length_input: String,
+    history_length_error: Option<String>,
+    disk_filter_input: String,
+    disk_filter_error: Option<String>,
+    mount_filter_input: String,
+    mount_filter_error: Option<String>,
+    sensor_filter_input: String,
+    sensor_filter_error: Option<String>,
+}
+
+impl Default for YourApp {
+    fn default() -> Self {
+        Self {
+            core: Core::default(),
+            popup: None,
+            stats: Vec::new(),
+            ram_stat_toggle: false,
+            disk_stat_toggle: false,
+            temp_stat_toggle: false,
+            cpu_stat_toggle: false,
+            network_stat_toggle: false,
+            #[cfg(feature = "battery")]
+            battery_stat_toggle: false,
+            process_stat_toggle: false,
+            system: sysinfo::System::new(),
+            last_cpu_refresh: None,
+            process_prev_disk_usage: HashMap::new(),
+            process_disk_rates: HashMap::new(),
+            process_count: 5,
+            process_count_input: String::from("5"),
+            process_count_error: None,
+            networks: sysinfo::Networks::new(),
+            last_network_sample: None,
+            network_prev_bytes: HashMap::new(),
+            network_rates: HashMap::new(),
+            config_handler: None,
+            config: VitalsAppletConfig::default(),
+            refresh_interval: std::time::Duration::from_secs(5),
+            refresh_interval_input: String::from("5s"),
+            refresh_interval_error: None,
+            history: HashMap::new(),
+            history_length: 60,
+            history_length_input: String::from("60"),
+            history_length_error: None,
+            disk_filter_input: String::new(),
+            disk_filter_error: None,
+            mount_filter_input: String::new(),
+            mount_filter_error: None,
+            sensor_filter_input: String::new(),
+            sensor_filter_error: None,
+        }
+    }
 }
 
 fn to_gb(bytes: u64) -> f64 {
@@ -64,11 +157,18 @@ fn get_ram_usage(name: &str) -> String {
     ram_usage_text
 }
 
-fn get_storage_usage(name: &str) -> String {
+fn get_storage_usage(disk_filter: &RegexFilter, mount_filter: &RegexFilter, name: &str) -> String {
     let mut disks = sysinfo::Disks::new();
     disks.refresh_list();
     let mut storage_usage_text = String::from("");
     for disk in &mut disks {
+        let mount_point = disk.mount_point().to_string_lossy().to_string();
+        if !filter_allows(disk_filter, disk.name().to_string_lossy().as_ref())
+            || !filter_allows(mount_filter, &mount_point)
+        {
+            continue;
+        }
+
         if disk.name().eq(name) {
             storage_usage_text = format!("Disk {:.2} GB", to_gb(disk.available_space()));
         }
@@ -119,17 +219,21 @@ fn get_ram_stats() -> Vec<(String, String)> {
     ram_stats
 }
 
-fn get_disks() -> Vec<(String, String)> {
+fn get_disks(disk_filter: &RegexFilter, mount_filter: &RegexFilter) -> Vec<(String, String)> {
     let mut disks = sysinfo::Disks::new();
     disks.refresh_list();
 
     let mut disk_availables: HashMap<String, String> = HashMap::new();
 
     for disk in &mut disks {
-        disk_availables.insert(
-            disk.name().to_str().unwrap().to_string(),
-            format!("{:.2}", to_gb(disk.available_space())),
-        );
+        let name = disk.name().to_str().unwrap().to_string();
+        let mount_point = disk.mount_point().to_string_lossy().to_string();
+
+        if !filter_allows(disk_filter, &name) || !filter_allows(mount_filter, &mount_point) {
+            continue;
+        }
+
+        disk_availables.insert(name, format!("{:.2}", to_gb(disk.available_space())));
     }
 
     let mut disk_availables: Vec<(String, String)> = disk_availables
@@ -142,29 +246,51 @@ fn get_disks() -> Vec<(String, String)> {
     disk_availables
 }
 
-fn get_temps() -> Vec<(String, String)> {
+/// Lists sensor readings, filtered by `sensor_filter` and with duplicate
+/// labels disambiguated by appending a stable `(n)` suffix.
+fn get_temps(sensor_filter: &RegexFilter) -> Vec<(String, String)> {
     let mut components = sysinfo::Components::new();
     components.refresh_list();
 
-    let mut temps = components
-        .iter()
-        .map(|x| (x.label().to_string(), format!("{}", x.temperature() as u32)))
-        .collect::<Vec<(String, String)>>();
+    let mut label_counts: HashMap<String, usize> = HashMap::new();
+    let mut temps: Vec<(String, String)> = vec![];
+    let mut readings: Vec<u32> = vec![];
+
+    for component in components.iter() {
+        let label = component.label().to_string();
+        if !filter_allows(sensor_filter, &label) {
+            continue;
+        }
 
-    let max_temp = components.iter().map(|x| x.temperature() as u32).max();
+        let temperature = component.temperature() as u32;
+        let count = label_counts.entry(label.clone()).or_insert(0);
+        let unique_label = if *count == 0 {
+            label
+        } else {
+            format!("{} ({})", label, count)
+        };
+        *count += 1;
 
-    let min_temp = components.iter().map(|x| x.temperature() as u32).min();
+        readings.push(temperature);
+        temps.push((unique_label, format!("{}", temperature)));
+    }
 
     temps.sort_by(|a, b| a.0.cmp(&b.0));
 
-    temps.push((fl!("max-temp"), format!("{}", max_temp.unwrap_or(0))));
-    temps.push((fl!("min-temp"), format!("{}", min_temp.unwrap_or(0))));
+    temps.push((
+        fl!("max-temp"),
+        format!("{}", readings.iter().max().copied().unwrap_or(0)),
+    ));
+    temps.push((
+        fl!("min-temp"),
+        format!("{}", readings.iter().min().copied().unwrap_or(0)),
+    ));
 
     temps
 }
 
-fn get_temp_usage(name: &str) -> String {
-    for (temp_name, temp) in get_temps() {
+fn get_temp_usage(sensor_filter: &RegexFilter, name: &str) -> String {
+    for (temp_name, temp) in get_temps(sensor_filter) {
         if name == temp_name {
             return format!("Temp {} °C", temp);
         }
@@ -173,6 +299,148 @@ fn get_temp_usage(name: &str) -> String {
     String::from("")
 }
 
+fn get_cpu_stats(system: &sysinfo::System) -> Vec<(String, String)> {
+    let mut cpu_stats = vec![];
+
+    cpu_stats.push((
+        fl!("total-cpu"),
+        format!("{:.0}", system.global_cpu_info().cpu_usage()),
+    ));
+
+    for (i, cpu) in system.cpus().iter().enumerate() {
+        cpu_stats.push((
+            format!("{} {}", fl!("cpu-core"), i),
+            format!("{:.0}", cpu.cpu_usage()),
+        ));
+    }
+
+    cpu_stats
+}
+
+fn get_cpu_usage(system: &sysinfo::System, name: &str) -> String {
+    for (cpu_name, usage) in get_cpu_stats(system) {
+        if name == cpu_name {
+            return format!("CPU {}%", usage);
+        }
+    }
+
+    String::from("")
+}
+
+fn format_rate(bytes_per_sec: f64) -> String {
+    if bytes_per_sec >= 1_000_000.0 {
+        format!("{:.1} MB/s", bytes_per_sec / 1_000_000.0)
+    } else {
+        format!("{:.1} KB/s", bytes_per_sec / 1_000.0)
+    }
+}
+
+fn get_network_stats(rates: &HashMap<String, (f64, f64)>) -> Vec<(String, String)> {
+    let mut network_stats: Vec<(String, String)> = rates
+        .iter()
+        .map(|(name, (down, up))| {
+            (
+                name.clone(),
+                format!("↓{} ↑{}", format_rate(*down), format_rate(*up)),
+            )
+        })
+        .collect();
+
+    network_stats.sort_by(|a, b| a.0.cmp(&b.0));
+
+    network_stats
+}
+
+fn get_network_usage(rates: &HashMap<String, (f64, f64)>, name: &str) -> String {
+    for (iface_name, value) in get_network_stats(rates) {
+        if name == iface_name {
+            return format!("{} {}", iface_name, value);
+        }
+    }
+
+    String::from("")
+}
+
+/// Extracts the raw numeric reading behind a stat's display label, for the
+/// sparkline history. Returns `None` for stats without a single plottable
+/// number (e.g. battery, which bundles a percentage and a duration).
+fn numeric_sample(
+    stat_type: &StatType,
+    system: &sysinfo::System,
+    network_rates: &HashMap<String, (f64, f64)>,
+    config: &VitalsAppletConfig,
+) -> Option<f64> {
+    match stat_type {
+        StatType::Ram(name) => get_ram_stats()
+            .into_iter()
+            .find(|(stat_name, _)| stat_name == name)
+            .and_then(|(_, value)| value.trim_end_matches(" GB").parse().ok()),
+        StatType::Disk(name) => get_disks(&config.disk_filter, &config.mount_filter)
+            .into_iter()
+            .find(|(stat_name, _)| stat_name == name)
+            .and_then(|(_, value)| value.parse().ok()),
+        StatType::MaxTemp(name) => get_temps(&config.sensor_filter)
+            .into_iter()
+            .find(|(stat_name, _)| stat_name == name)
+            .and_then(|(_, value)| value.parse().ok()),
+        StatType::Cpu(name) => get_cpu_stats(system)
+            .into_iter()
+            .find(|(stat_name, _)| stat_name == name)
+            .and_then(|(_, value)| value.parse().ok()),
+        StatType::Network(name) => network_rates.get(name).map(|(down, _)| *down),
+        #[cfg(feature = "battery")]
+        StatType::Battery(_) => None,
+    }
+}
+
+struct ProcessRow {
+    pid: u32,
+    name: String,
+    cpu_percent: f32,
+    memory_bytes: u64,
+    read_rate: f64,
+    write_rate: f64,
+}
+
+/// Lists the top `count` processes by `sort_key`, with per-process disk I/O
+/// rates read from the caller's held delta cache.
+fn get_process_rows(
+    system: &sysinfo::System,
+    disk_rates: &HashMap<sysinfo::Pid, (f64, f64)>,
+    sort_key: ProcessSortKey,
+    count: usize,
+) -> Vec<ProcessRow> {
+    let mut rows: Vec<ProcessRow> = system
+        .processes()
+        .iter()
+        .map(|(pid, process)| {
+            let (read_rate, write_rate) = disk_rates.get(pid).copied().unwrap_or((0.0, 0.0));
+
+            ProcessRow {
+                pid: pid.as_u32(),
+                name: process.name().to_string_lossy().to_string(),
+                cpu_percent: process.cpu_usage(),
+                memory_bytes: process.memory(),
+                read_rate,
+                write_rate,
+            }
+        })
+        .collect();
+
+    match sort_key {
+        ProcessSortKey::Cpu => rows.sort_by(|a, b| {
+            b.cpu_percent
+                .partial_cmp(&a.cpu_percent)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        }),
+        ProcessSortKey::Memory => rows.sort_by(|a, b| b.memory_bytes.cmp(&a.memory_bytes)),
+    }
+
+    rows.truncate(count);
+
+    rows
+}
+
 #[derive(Debug, Clone)]
 pub enum Message {
     TogglePopup,
@@ -182,6 +450,21 @@ pub enum Message {
     RamStatsToggle(bool),
     DiskStatsToggle(bool),
     TempStatsToggle(bool),
+    CpuStatsToggle(bool),
+    NetworkStatsToggle(bool),
+    #[cfg(feature = "battery")]
+    BatteryStatsToggle(bool),
+    RefreshIntervalInputChanged(String),
+    HistoryLengthInputChanged(String),
+    DiskFilterInputChanged(String),
+    DiskFilterExcludeToggle(bool),
+    MountFilterInputChanged(String),
+    MountFilterExcludeToggle(bool),
+    SensorFilterInputChanged(String),
+    SensorFilterExcludeToggle(bool),
+    ProcessStatsToggle(bool),
+    ProcessSortKeyToggle,
+    ProcessCountInputChanged(String),
 }
 
 impl YourApp {
@@ -203,15 +486,35 @@ impl YourApp {
                 StatType::Ram(_) => format!("({} GB)", value),
                 StatType::Disk(_) => format!("({} GB)", value),
                 StatType::MaxTemp(_) => format!("({} °C)", value),
+                StatType::Cpu(_) => format!("({}%)", value),
+                StatType::Network(_) => format!("({})", value),
+                #[cfg(feature = "battery")]
+                StatType::Battery(_) => format!("({})", value),
             };
 
-            let item = item_row(vec![
+            let mut row_children = vec![
                 text(name.clone()).wrap(Wrap::Word).width(125).into(),
                 horizontal_space(Length::Fill).into(),
                 text(formatted_value)
                     .wrap(Wrap::Word)
                     .horizontal_alignment(Horizontal::Left)
                     .into(),
+            ];
+
+            if is_checked {
+                if let Some(samples) = self.history.get(&stat_type) {
+                    row_children.push(
+                        Canvas::new(Sparkline {
+                            samples: samples.iter().copied().collect(),
+                        })
+                        .width(Length::Fixed(60.0))
+                        .height(Length::Fixed(24.0))
+                        .into(),
+                    );
+                }
+            }
+
+            row_children.push(
                 toggler(None, is_checked, move |value| {
                     Message::ToggleStat(Stat {
                         stat_type: stat_type.clone(),
@@ -220,8 +523,9 @@ impl YourApp {
                     })
                 })
                 .into(),
-            ])
-            .into();
+            );
+
+            let item = item_row(row_children).into();
 
             children.push(item);
         }
@@ -275,8 +579,61 @@ impl Application for YourApp {
     }
 
     fn init(core: Core, _flags: Self::Flags) -> (Self, Command<Self::Message>) {
+        let mut system = sysinfo::System::new();
+        system.refresh_cpu();
+
+        let mut networks = sysinfo::Networks::new();
+        networks.refresh_list();
+
+        let mut network_prev_bytes = HashMap::new();
+        let mut network_rates = HashMap::new();
+        for (name, data) in &networks {
+            network_prev_bytes.insert(name.clone(), (data.total_received(), data.total_transmitted()));
+            network_rates.insert(name.clone(), (0.0, 0.0));
+        }
+
+        let config_handler =
+            cosmic_config::Config::new(Self::APP_ID, VitalsAppletConfig::VERSION).ok();
+        let config = config_handler
+            .as_ref()
+            .map(|handler| {
+                VitalsAppletConfig::get_entry(handler).unwrap_or_else(|(_errors, config)| config)
+            })
+            .unwrap_or_default();
+
+        let refresh_interval = parse_refresh_interval(&config.refresh_interval)
+            .unwrap_or(std::time::Duration::from_secs(5));
+        let refresh_interval_input = config.refresh_interval.clone();
+
+        let history_length = config.history_length.max(MINIMUM_HISTORY_LENGTH);
+        let history_length_input = history_length.to_string();
+
+        let disk_filter_input = config.disk_filter.pattern.clone();
+        let mount_filter_input = config.mount_filter.pattern.clone();
+        let sensor_filter_input = config.sensor_filter.pattern.clone();
+
+        let process_count = config.process_count.max(MINIMUM_PROCESS_COUNT);
+        let process_count_input = process_count.to_string();
+
         let app = YourApp {
             core,
+            system,
+            last_cpu_refresh: Some(Instant::now()),
+            process_count,
+            process_count_input,
+            networks,
+            last_network_sample: Some(Instant::now()),
+            network_prev_bytes,
+            network_rates,
+            config_handler,
+            config,
+            refresh_interval,
+            refresh_interval_input,
+            history_length,
+            history_length_input,
+            disk_filter_input,
+            mount_filter_input,
+            sensor_filter_input,
             ..Default::default()
         };
 
@@ -305,18 +662,66 @@ impl Application for YourApp {
     }
 
     fn subscription(&self) -> Subscription<Self::Message> {
-        time::every(std::time::Duration::from_secs(5)).map(|_| Message::Tick)
+        time::every(self.refresh_interval).map(|_| Message::Tick)
     }
 
     fn view_window(&self, _id: Id) -> Element<Self::Message> {
         let ram_list =
             column::with_children(self.stat_list(get_ram_stats(), StatType::Ram)).spacing(5);
 
-        let disks_list =
-            column::with_children(self.stat_list(get_disks(), StatType::Disk)).spacing(5);
-
-        let temp_list =
-            column::with_children(self.stat_list(get_temps(), StatType::MaxTemp)).spacing(5);
+        let disks_list = column::with_children(self.stat_list(
+            get_disks(&self.config.disk_filter, &self.config.mount_filter),
+            StatType::Disk,
+        ))
+        .spacing(5);
+
+        let temp_list = column::with_children(
+            self.stat_list(get_temps(&self.config.sensor_filter), StatType::MaxTemp),
+        )
+        .spacing(5);
+
+        let cpu_list = column::with_children(self.stat_list(get_cpu_stats(&self.system), StatType::Cpu))
+            .spacing(5);
+
+        let network_list = column::with_children(
+            self.stat_list(get_network_stats(&self.network_rates), StatType::Network),
+        )
+        .spacing(5);
+
+        #[cfg(feature = "battery")]
+        let battery_list =
+            column::with_children(self.stat_list(get_battery_stats(), StatType::Battery)).spacing(5);
+
+        let process_list = column::with_children(
+            get_process_rows(
+                &self.system,
+                &self.process_disk_rates,
+                self.config.process_sort,
+                self.process_count,
+            )
+            .into_iter()
+            .map(|row| {
+                item_row(vec![
+                    text(format!("{} ({})", row.name, row.pid))
+                        .wrap(Wrap::Word)
+                        .width(125)
+                        .into(),
+                    horizontal_space(Length::Fill).into(),
+                    text(format!(
+                        "{:.0}% {:.2} GB ↓{} ↑{}",
+                        row.cpu_percent,
+                        to_gb(row.memory_bytes),
+                        format_rate(row.read_rate),
+                        format_rate(row.write_rate)
+                    ))
+                    .wrap(Wrap::Word)
+                    .into(),
+                ])
+                .into()
+            })
+            .collect(),
+        )
+        .spacing(5);
 
         let mut content_list = list_column().add(self.dropdown_menu_button(
             self.temp_stat_toggle,
@@ -351,6 +756,176 @@ impl Application for YourApp {
             content_list = content_list.add(ram_list);
         }
 
+        content_list = content_list.add(self.dropdown_menu_button(
+            self.cpu_stat_toggle,
+            fl!("cpu-usage"),
+            Message::CpuStatsToggle(!self.cpu_stat_toggle),
+        ));
+
+        if self.cpu_stat_toggle {
+            content_list = content_list.add(cpu_list);
+        }
+
+        content_list = content_list.add(self.dropdown_menu_button(
+            self.network_stat_toggle,
+            fl!("network-usage"),
+            Message::NetworkStatsToggle(!self.network_stat_toggle),
+        ));
+
+        if self.network_stat_toggle {
+            content_list = content_list.add(network_list);
+        }
+
+        #[cfg(feature = "battery")]
+        {
+            content_list = content_list.add(self.dropdown_menu_button(
+                self.battery_stat_toggle,
+                fl!("battery-usage"),
+                Message::BatteryStatsToggle(!self.battery_stat_toggle),
+            ));
+
+            if self.battery_stat_toggle {
+                content_list = content_list.add(battery_list);
+            }
+        }
+
+        content_list = content_list.add(self.dropdown_menu_button(
+            self.process_stat_toggle,
+            fl!("process-usage"),
+            Message::ProcessStatsToggle(!self.process_stat_toggle),
+        ));
+
+        if self.process_stat_toggle {
+            let sort_label = match self.config.process_sort {
+                ProcessSortKey::Cpu => fl!("process-sort-cpu"),
+                ProcessSortKey::Memory => fl!("process-sort-memory"),
+            };
+
+            content_list = content_list.add(item_row(vec![
+                text(fl!("process-sort"))
+                    .wrap(Wrap::Word)
+                    .width(125)
+                    .into(),
+                horizontal_space(Length::Fill).into(),
+                button(text(sort_label))
+                    .on_press(Message::ProcessSortKeyToggle)
+                    .into(),
+            ]));
+
+            content_list = content_list.add(item_row(vec![
+                text(fl!("process-count"))
+                    .wrap(Wrap::Word)
+                    .width(125)
+                    .into(),
+                horizontal_space(Length::Fill).into(),
+                text_input("5", &self.process_count_input)
+                    .on_input(Message::ProcessCountInputChanged)
+                    .width(Length::Fixed(80.0))
+                    .into(),
+            ]));
+
+            if let Some(err) = &self.process_count_error {
+                content_list = content_list.add(text(err.clone()).size(12));
+            }
+
+            content_list = content_list.add(process_list);
+        }
+
+        content_list = content_list.add(item_row(vec![
+            text(fl!("refresh-interval"))
+                .wrap(Wrap::Word)
+                .width(125)
+                .into(),
+            horizontal_space(Length::Fill).into(),
+            text_input("5s", &self.refresh_interval_input)
+                .on_input(Message::RefreshIntervalInputChanged)
+                .width(Length::Fixed(80.0))
+                .into(),
+        ]));
+
+        if let Some(err) = &self.refresh_interval_error {
+            content_list = content_list.add(text(err.clone()).size(12));
+        }
+
+        content_list = content_list.add(item_row(vec![
+            text(fl!("history-length"))
+                .wrap(Wrap::Word)
+                .width(125)
+                .into(),
+            horizontal_space(Length::Fill).into(),
+            text_input("60", &self.history_length_input)
+                .on_input(Message::HistoryLengthInputChanged)
+                .width(Length::Fixed(80.0))
+                .into(),
+        ]));
+
+        if let Some(err) = &self.history_length_error {
+            content_list = content_list.add(text(err.clone()).size(12));
+        }
+
+        content_list = content_list.add(item_row(vec![
+            text(fl!("disk-filter")).wrap(Wrap::Word).width(125).into(),
+            horizontal_space(Length::Fill).into(),
+            text_input(fl!("filter-pattern-placeholder"), &self.disk_filter_input)
+                .on_input(Message::DiskFilterInputChanged)
+                .width(Length::Fixed(120.0))
+                .into(),
+            toggler(
+                Some(fl!("filter-exclude")),
+                self.config.disk_filter.is_exclude,
+                Message::DiskFilterExcludeToggle,
+            )
+            .into(),
+        ]));
+
+        if let Some(err) = &self.disk_filter_error {
+            content_list = content_list.add(text(err.clone()).size(12));
+        }
+
+        content_list = content_list.add(item_row(vec![
+            text(fl!("mount-filter"))
+                .wrap(Wrap::Word)
+                .width(125)
+                .into(),
+            horizontal_space(Length::Fill).into(),
+            text_input(fl!("filter-pattern-placeholder"), &self.mount_filter_input)
+                .on_input(Message::MountFilterInputChanged)
+                .width(Length::Fixed(120.0))
+                .into(),
+            toggler(
+                Some(fl!("filter-exclude")),
+                self.config.mount_filter.is_exclude,
+                Message::MountFilterExcludeToggle,
+            )
+            .into(),
+        ]));
+
+        if let Some(err) = &self.mount_filter_error {
+            content_list = content_list.add(text(err.clone()).size(12));
+        }
+
+        content_list = content_list.add(item_row(vec![
+            text(fl!("sensor-filter"))
+                .wrap(Wrap::Word)
+                .width(125)
+                .into(),
+            horizontal_space(Length::Fill).into(),
+            text_input(fl!("filter-pattern-placeholder"), &self.sensor_filter_input)
+                .on_input(Message::SensorFilterInputChanged)
+                .width(Length::Fixed(120.0))
+                .into(),
+            toggler(
+                Some(fl!("filter-exclude")),
+                self.config.sensor_filter.is_exclude,
+                Message::SensorFilterExcludeToggle,
+            )
+            .into(),
+        ]));
+
+        if let Some(err) = &self.sensor_filter_error {
+            content_list = content_list.add(text(err.clone()).size(12));
+        }
+
         self.core.applet.popup_container(content_list).into()
     }
 
@@ -383,27 +958,300 @@ impl Application for YourApp {
                 if stat.show {
                     stat.label = match stat.stat_type {
                         StatType::Ram(ref name) => get_ram_usage(name),
-                        StatType::Disk(ref name) => get_storage_usage(name),
-                        StatType::MaxTemp(ref name) => get_temp_usage(name),
+                        StatType::Disk(ref name) => get_storage_usage(
+                            &self.config.disk_filter,
+                            &self.config.mount_filter,
+                            name,
+                        ),
+                        StatType::MaxTemp(ref name) => {
+                            get_temp_usage(&self.config.sensor_filter, name)
+                        }
+                        StatType::Cpu(ref name) => get_cpu_usage(&self.system, name),
+                        StatType::Network(ref name) => get_network_usage(&self.network_rates, name),
+                        #[cfg(feature = "battery")]
+                        StatType::Battery(ref name) => get_battery_usage(name),
                     };
 
+                    self.history.entry(stat.stat_type.clone()).or_default();
                     self.stats.push(stat);
                 } else {
                     self.stats.retain(|x| x.stat_type != stat.stat_type);
+                    self.history.remove(&stat.stat_type);
                 }
             }
             Message::Tick => {
+                let now = Instant::now();
+                let last_cpu_refresh = self.last_cpu_refresh;
+                let should_refresh_cpu = last_cpu_refresh
+                    .map(|last| now.duration_since(last) >= sysinfo::MINIMUM_CPU_UPDATE_INTERVAL)
+                    .unwrap_or(true);
+
+                if should_refresh_cpu {
+                    self.system.refresh_cpu();
+                    self.system.refresh_processes();
+                    self.last_cpu_refresh = Some(now);
+
+                    let live_pids: std::collections::HashSet<sysinfo::Pid> =
+                        self.system.processes().keys().copied().collect();
+                    self.process_prev_disk_usage
+                        .retain(|pid, _| live_pids.contains(pid));
+                    self.process_disk_rates
+                        .retain(|pid, _| live_pids.contains(pid));
+
+                    let process_elapsed_secs = last_cpu_refresh
+                        .map(|last| now.duration_since(last).as_secs_f64())
+                        .unwrap_or(0.0);
+
+                    if process_elapsed_secs > 0.0 {
+                        for (pid, process) in self.system.processes() {
+                            let disk_usage = process.disk_usage();
+                            let (prev_read, prev_write) = self
+                                .process_prev_disk_usage
+                                .get(pid)
+                                .copied()
+                                .unwrap_or((disk_usage.total_read_bytes, disk_usage.total_written_bytes));
+
+                            let read_rate = disk_usage
+                                .total_read_bytes
+                                .saturating_sub(prev_read) as f64
+                                / process_elapsed_secs;
+                            let write_rate = disk_usage
+                                .total_written_bytes
+                                .saturating_sub(prev_write) as f64
+                                / process_elapsed_secs;
+
+                            self.process_disk_rates.insert(*pid, (read_rate, write_rate));
+                            self.process_prev_disk_usage.insert(
+                                *pid,
+                                (disk_usage.total_read_bytes, disk_usage.total_written_bytes),
+                            );
+                        }
+                    }
+                }
+
+                let elapsed_secs = self
+                    .last_network_sample
+                    .map(|last| now.duration_since(last).as_secs_f64())
+                    .unwrap_or(0.0);
+
+                self.networks.refresh();
+
+                let live_interfaces: std::collections::HashSet<&str> =
+                    self.networks.iter().map(|(name, _)| name.as_str()).collect();
+                self.network_prev_bytes
+                    .retain(|name, _| live_interfaces.contains(name.as_str()));
+                self.network_rates
+                    .retain(|name, _| live_interfaces.contains(name.as_str()));
+
+                if elapsed_secs > 0.0 {
+                    for (name, data) in &self.networks {
+                        let new_received = data.total_received();
+                        let new_transmitted = data.total_transmitted();
+                        let (prev_received, prev_transmitted) = self
+                            .network_prev_bytes
+                            .get(name)
+                            .copied()
+                            .unwrap_or((new_received, new_transmitted));
+
+                        let down_rate =
+                            new_received.saturating_sub(prev_received) as f64 / elapsed_secs;
+                        let up_rate =
+                            new_transmitted.saturating_sub(prev_transmitted) as f64 / elapsed_secs;
+
+                        self.network_rates
+                            .insert(name.clone(), (down_rate, up_rate));
+                        self.network_prev_bytes
+                            .insert(name.clone(), (new_received, new_transmitted));
+                    }
+                }
+                self.last_network_sample = Some(now);
+
                 for stat in &mut self.stats {
+                    if let Some(sample) = numeric_sample(
+                        &stat.stat_type,
+                        &self.system,
+                        &self.network_rates,
+                        &self.config,
+                    ) {
+                        let history_length = self.history_length;
+                        let series = self.history.entry(stat.stat_type.clone()).or_default();
+                        series.push_back(sample);
+                        while series.len() > history_length {
+                            series.pop_front();
+                        }
+                    }
+
                     stat.label = match stat.stat_type {
                         StatType::Ram(ref name) => get_ram_usage(name),
-                        StatType::Disk(ref name) => get_storage_usage(name),
-                        StatType::MaxTemp(ref name) => get_temp_usage(name),
+                        StatType::Disk(ref name) => get_storage_usage(
+                            &self.config.disk_filter,
+                            &self.config.mount_filter,
+                            name,
+                        ),
+                        StatType::MaxTemp(ref name) => {
+                            get_temp_usage(&self.config.sensor_filter, name)
+                        }
+                        StatType::Cpu(ref name) => get_cpu_usage(&self.system, name),
+                        StatType::Network(ref name) => get_network_usage(&self.network_rates, name),
+                        #[cfg(feature = "battery")]
+                        StatType::Battery(ref name) => get_battery_usage(name),
                     }
                 }
             }
             Message::RamStatsToggle(toggle) => self.ram_stat_toggle = toggle,
             Message::DiskStatsToggle(toggle) => self.disk_stat_toggle = toggle,
             Message::TempStatsToggle(toggle) => self.temp_stat_toggle = toggle,
+            Message::CpuStatsToggle(toggle) => self.cpu_stat_toggle = toggle,
+            Message::NetworkStatsToggle(toggle) => self.network_stat_toggle = toggle,
+            #[cfg(feature = "battery")]
+            Message::BatteryStatsToggle(toggle) => self.battery_stat_toggle = toggle,
+            Message::RefreshIntervalInputChanged(input) => {
+                self.refresh_interval_input = input.clone();
+
+                match parse_refresh_interval(&input) {
+                    Ok(duration) => {
+                        self.refresh_interval_error = None;
+                        self.refresh_interval = duration;
+                        self.config.refresh_interval = input;
+
+                        if let Some(handler) = &self.config_handler {
+                            let _ = self.config.write_entry(handler);
+                        }
+                    }
+                    Err(err) => self.refresh_interval_error = Some(err),
+                }
+            }
+            Message::HistoryLengthInputChanged(input) => {
+                self.history_length_input = input.clone();
+
+                match input.trim().parse::<usize>() {
+                    Ok(length) if length >= MINIMUM_HISTORY_LENGTH => {
+                        self.history_length_error = None;
+                        self.history_length = length;
+                        self.config.history_length = length;
+
+                        for series in self.history.values_mut() {
+                            while series.len() > length {
+                                series.pop_front();
+                            }
+                        }
+
+                        if let Some(handler) = &self.config_handler {
+                            let _ = self.config.write_entry(handler);
+                        }
+                    }
+                    Ok(_) => {
+                        self.history_length_error = Some(format!(
+                            "history length must be at least {}",
+                            MINIMUM_HISTORY_LENGTH
+                        ))
+                    }
+                    Err(_) => {
+                        self.history_length_error = Some(String::from("history length must be a number"))
+                    }
+                }
+            }
+            Message::DiskFilterInputChanged(input) => {
+                self.disk_filter_input = input.clone();
+
+                match validate_filter_pattern(&input) {
+                    Ok(()) => {
+                        self.disk_filter_error = None;
+                        self.config.disk_filter.pattern = input;
+
+                        if let Some(handler) = &self.config_handler {
+                            let _ = self.config.write_entry(handler);
+                        }
+                    }
+                    Err(err) => self.disk_filter_error = Some(err),
+                }
+            }
+            Message::DiskFilterExcludeToggle(is_exclude) => {
+                self.config.disk_filter.is_exclude = is_exclude;
+
+                if let Some(handler) = &self.config_handler {
+                    let _ = self.config.write_entry(handler);
+                }
+            }
+            Message::MountFilterInputChanged(input) => {
+                self.mount_filter_input = input.clone();
+
+                match validate_filter_pattern(&input) {
+                    Ok(()) => {
+                        self.mount_filter_error = None;
+                        self.config.mount_filter.pattern = input;
+
+                        if let Some(handler) = &self.config_handler {
+                            let _ = self.config.write_entry(handler);
+                        }
+                    }
+                    Err(err) => self.mount_filter_error = Some(err),
+                }
+            }
+            Message::MountFilterExcludeToggle(is_exclude) => {
+                self.config.mount_filter.is_exclude = is_exclude;
+
+                if let Some(handler) = &self.config_handler {
+                    let _ = self.config.write_entry(handler);
+                }
+            }
+            Message::SensorFilterInputChanged(input) => {
+                self.sensor_filter_input = input.clone();
+
+                match validate_filter_pattern(&input) {
+                    Ok(()) => {
+                        self.sensor_filter_error = None;
+                        self.config.sensor_filter.pattern = input;
+
+                        if let Some(handler) = &self.config_handler {
+                            let _ = self.config.write_entry(handler);
+                        }
+                    }
+                    Err(err) => self.sensor_filter_error = Some(err),
+                }
+            }
+            Message::SensorFilterExcludeToggle(is_exclude) => {
+                self.config.sensor_filter.is_exclude = is_exclude;
+
+                if let Some(handler) = &self.config_handler {
+                    let _ = self.config.write_entry(handler);
+                }
+            }
+            Message::ProcessStatsToggle(toggle) => self.process_stat_toggle = toggle,
+            Message::ProcessSortKeyToggle => {
+                self.config.process_sort = match self.config.process_sort {
+                    ProcessSortKey::Cpu => ProcessSortKey::Memory,
+                    ProcessSortKey::Memory => ProcessSortKey::Cpu,
+                };
+
+                if let Some(handler) = &self.config_handler {
+                    let _ = self.config.write_entry(handler);
+                }
+            }
+            Message::ProcessCountInputChanged(input) => {
+                self.process_count_input = input.clone();
+
+                match input.trim().parse::<usize>() {
+                    Ok(count) if count >= MINIMUM_PROCESS_COUNT => {
+                        self.process_count_error = None;
+                        self.process_count = count;
+                        self.config.process_count = count;
+
+                        if let Some(handler) = &self.config_handler {
+                            let _ = self.config.write_entry(handler);
+                        }
+                    }
+                    Ok(_) => {
+                        self.process_count_error = Some(format!(
+                            "process count must be at least {}",
+                            MINIMUM_PROCESS_COUNT
+                        ))
+                    }
+                    Err(_) => {
+                        self.process_count_error = Some(String::from("process count must be a number"))
+                    }
+                }
+            }
         }
         Command::none()
     }
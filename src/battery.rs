@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use battery::{Manager, State};
+
+fn format_duration(seconds: f32) -> String {
+    let total_minutes = (seconds / 60.0).round() as u64;
+    let hours = total_minutes / 60;
+    let minutes = total_minutes % 60;
+
+    if hours > 0 {
+        format!("{}h{:02}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+fn state_label(state: State) -> &'static str {
+    match state {
+        State::Full => "full",
+        State::Empty => "empty",
+        State::Charging => "charging",
+        State::Discharging => "discharging",
+        _ => "unknown",
+    }
+}
+
+pub fn get_battery_stats() -> Vec<(String, String)> {
+    let mut battery_stats = vec![];
+
+    let Ok(manager) = Manager::new() else {
+        return battery_stats;
+    };
+
+    let Ok(batteries) = manager.batteries() else {
+        return battery_stats;
+    };
+
+    for (i, result) in batteries.enumerate() {
+        let Ok(battery) = result else {
+            continue;
+        };
+
+        let percent = battery.state_of_charge().value * 100.0;
+
+        let time_left = match battery.state() {
+            State::Charging => battery.time_to_full(),
+            State::Discharging => battery.time_to_empty(),
+            _ => None,
+        };
+
+        let detail = match time_left {
+            Some(time) => format_duration(time.value),
+            None => state_label(battery.state()).to_string(),
+        };
+
+        battery_stats.push((
+            format!("Battery {}", i),
+            format!("{:.0}% ({})", percent, detail),
+        ));
+    }
+
+    battery_stats
+}
+
+pub fn get_battery_usage(name: &str) -> String {
+    for (battery_name, value) in get_battery_stats() {
+        if name == battery_name {
+            return format!("🔋 {}", value);
+        }
+    }
+
+    String::from("")
+}
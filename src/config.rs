@@ -1,10 +1,167 @@
+use std::time::Duration;
+
 use cosmic::cosmic_config::{self, cosmic_config_derive::CosmicConfigEntry, CosmicConfigEntry};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 use crate::app::Stat;
 
-#[derive(Default, Debug, Clone, Serialize, Deserialize, CosmicConfigEntry, PartialEq, Eq)]
+/// Floor below which a refresh interval would just burn CPU waking the applet.
+pub const MINIMUM_REFRESH_INTERVAL: Duration = Duration::from_millis(250);
+const DEFAULT_REFRESH_INTERVAL: &str = "5s";
+/// Minimum backlog so a sparkline can hold at least two points to draw.
+pub const MINIMUM_HISTORY_LENGTH: usize = 2;
+const DEFAULT_HISTORY_LENGTH: usize = 60;
+
+/// A user-editable regex filter. An empty pattern lets everything through,
+/// whether or not `is_exclude` is set.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RegexFilter {
+    pub pattern: String,
+    pub is_exclude: bool,
+}
+
+/// Which column the top-processes list is ranked by.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ProcessSortKey {
+    Cpu,
+    Memory,
+}
+
+impl Default for ProcessSortKey {
+    fn default() -> Self {
+        ProcessSortKey::Cpu
+    }
+}
+
+const DEFAULT_PROCESS_COUNT: usize = 5;
+/// Minimum process list size so the section always shows something.
+pub const MINIMUM_PROCESS_COUNT: usize = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize, CosmicConfigEntry, PartialEq, Eq)]
 #[version = 1]
 pub struct VitalsAppletConfig {
     pub stats: Vec<Stat>,
+    pub refresh_interval: String,
+    pub history_length: usize,
+    pub disk_filter: RegexFilter,
+    pub mount_filter: RegexFilter,
+    pub sensor_filter: RegexFilter,
+    pub process_sort: ProcessSortKey,
+    pub process_count: usize,
+}
+
+impl Default for VitalsAppletConfig {
+    fn default() -> Self {
+        Self {
+            stats: Vec::new(),
+            refresh_interval: DEFAULT_REFRESH_INTERVAL.to_string(),
+            history_length: DEFAULT_HISTORY_LENGTH,
+            disk_filter: RegexFilter::default(),
+            mount_filter: RegexFilter::default(),
+            sensor_filter: RegexFilter::default(),
+            process_sort: ProcessSortKey::default(),
+            process_count: DEFAULT_PROCESS_COUNT,
+        }
+    }
+}
+
+/// Checks whether `candidate` should be kept under `filter`.
+///
+/// An empty or invalid pattern always passes everything through, so a typo
+/// mid-edit in `view_window` never hides every disk/sensor.
+pub fn filter_allows(filter: &RegexFilter, candidate: &str) -> bool {
+    if filter.pattern.is_empty() {
+        return true;
+    }
+
+    let Ok(regex) = Regex::new(&filter.pattern) else {
+        return true;
+    };
+
+    let matches = regex.is_match(candidate);
+
+    if filter.is_exclude {
+        !matches
+    } else {
+        matches
+    }
+}
+
+/// Validates a filter pattern, surfacing a message for an invalid regex.
+pub fn validate_filter_pattern(pattern: &str) -> Result<(), String> {
+    if pattern.is_empty() {
+        return Ok(());
+    }
+
+    Regex::new(pattern).map(|_| ()).map_err(|err| err.to_string())
+}
+
+/// Parses human-friendly durations such as `"2s"`, `"500ms"`, or `"1m30s"`.
+///
+/// Supports `ms`/`s`/`m`/`h` suffixes, combined in sequence, and rejects
+/// anything below [`MINIMUM_REFRESH_INTERVAL`].
+pub fn parse_refresh_interval(input: &str) -> Result<Duration, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(String::from("duration cannot be empty"));
+    }
+
+    let mut total = Duration::ZERO;
+    let mut digits = String::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_ascii_digit() || c == '.' {
+            digits.push(c);
+            chars.next();
+            continue;
+        }
+
+        if digits.is_empty() {
+            return Err(format!("expected a number before '{}'", c));
+        }
+
+        let mut unit = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_digit() || c == '.' {
+                break;
+            }
+            unit.push(c);
+            chars.next();
+        }
+
+        let value: f64 = digits
+            .parse()
+            .map_err(|_| format!("invalid number '{}'", digits))?;
+        digits.clear();
+
+        let seconds = match unit.as_str() {
+            "ms" => value / 1000.0,
+            "s" => value,
+            "m" => value * 60.0,
+            "h" => value * 3600.0,
+            other => return Err(format!("unknown unit '{}'", other)),
+        };
+
+        let component = Duration::try_from_secs_f64(seconds)
+            .map_err(|_| format!("duration component '{}{}' is too large", value, unit))?;
+
+        total = total
+            .checked_add(component)
+            .ok_or_else(|| String::from("duration is too large"))?;
+    }
+
+    if !digits.is_empty() {
+        return Err(String::from("duration is missing a unit suffix"));
+    }
+
+    if total < MINIMUM_REFRESH_INTERVAL {
+        return Err(format!(
+            "refresh interval must be at least {}ms",
+            MINIMUM_REFRESH_INTERVAL.as_millis()
+        ));
+    }
+
+    Ok(total)
 }
@@ -1,7 +1,10 @@
 use app::YourApp;
 mod app;
+#[cfg(feature = "battery")]
+mod battery;
 mod config;
 mod core;
+mod sparkline;
 
 fn main() -> cosmic::iced::Result {
     cosmic::applet::run::<YourApp>(true, ())
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: GPL-3.0-only
+
+use cosmic::iced::widget::canvas::{self, Frame, Geometry, Path, Stroke};
+use cosmic::iced::{mouse, Color, Point, Rectangle, Renderer, Theme};
+
+/// Renders a bounded series of samples as a small line graph, auto-ranging
+/// the y-axis to the min/max in the buffer. Falls back to a flat line when
+/// every sample is equal; draws nothing with fewer than two samples.
+pub struct Sparkline {
+    pub samples: Vec<f64>,
+}
+
+impl<Message> canvas::Program<Message> for Sparkline {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        if self.samples.len() < 2 {
+            return vec![frame.into_geometry()];
+        }
+
+        let min = self.samples.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = self
+            .samples
+            .iter()
+            .cloned()
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        let width = f64::from(bounds.width);
+        let height = f64::from(bounds.height);
+        let step = width / (self.samples.len() - 1) as f64;
+
+        let y_for = |value: f64| -> f32 {
+            if (max - min).abs() < f64::EPSILON {
+                (height / 2.0) as f32
+            } else {
+                (height - ((value - min) / (max - min)) * height) as f32
+            }
+        };
+
+        let path = Path::new(|builder| {
+            builder.move_to(Point::new(0.0, y_for(self.samples[0])));
+
+            for (i, value) in self.samples.iter().enumerate().skip(1) {
+                builder.line_to(Point::new((step * i as f64) as f32, y_for(*value)));
+            }
+        });
+
+        frame.stroke(
+            &path,
+            Stroke::default()
+                .with_color(Color::from_rgb(0.3, 0.6, 1.0))
+                .with_width(1.5),
+        );
+
+        vec![frame.into_geometry()]
+    }
+}